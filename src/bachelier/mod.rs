@@ -0,0 +1,254 @@
+use statrs::distribution::{ContinuousCDF, Normal};
+
+use crate::black_scholes::OptionType;
+
+/// Bachelier (normal, arithmetic Brownian motion) option pricing model.
+///
+/// Black-Scholes assumes `dS = rS·dt + σS·dW`, which breaks down once the
+/// underlying can go negative (spreads, some rates and commodities). Bachelier
+/// instead assumes the forward follows `dF = σ·dW`, so `F` stays well-defined
+/// for any sign.
+pub struct Bachelier {
+    forward: f64,
+    strike: f64,
+    time_to_expiry: f64,
+    risk_free_rate: f64,
+    volatility: f64,
+    option_type: OptionType,
+}
+
+impl Bachelier {
+    /// Create a new Bachelier calculator. `volatility` is the *normal*
+    /// volatility, i.e. has the same units as `forward` and `strike`
+    /// (not a percentage of the forward, as in Black-Scholes).
+    pub fn new(
+        forward: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        option_type: OptionType,
+    ) -> Self {
+        Self {
+            forward,
+            strike,
+            time_to_expiry,
+            risk_free_rate,
+            volatility,
+            option_type,
+        }
+    }
+
+    fn pdf(x: f64) -> f64 {
+        (-0.5 * x.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt()
+    }
+
+    /// `d = (F - K) / (σ√T)`. Well-defined for any sign of `F - K`, including
+    /// a negative forward (unlike Black-Scholes' `ln(S/K)`).
+    fn d(&self) -> f64 {
+        (self.forward - self.strike) / (self.volatility * self.time_to_expiry.sqrt())
+    }
+
+    fn intrinsic(&self) -> f64 {
+        match self.option_type {
+            OptionType::Call => (self.forward - self.strike).max(0.0),
+            OptionType::Put => (self.strike - self.forward).max(0.0),
+        }
+    }
+
+    /// Calculate option price.
+    ///
+    /// As `σ → 0` this converges to the discounted intrinsic value, handled
+    /// explicitly below since `d` itself blows up in that limit.
+    pub fn price(&self) -> f64 {
+        let discount = (-self.risk_free_rate * self.time_to_expiry).exp();
+
+        if self.volatility <= 0.0 || self.time_to_expiry <= 0.0 {
+            return discount * self.intrinsic();
+        }
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let d = self.d();
+        let vol_sqrt_t = self.volatility * self.time_to_expiry.sqrt();
+
+        let undiscounted = match self.option_type {
+            OptionType::Call => vol_sqrt_t * (d * normal.cdf(d) + Self::pdf(d)),
+            OptionType::Put => vol_sqrt_t * (-d * normal.cdf(-d) + Self::pdf(d)),
+        };
+
+        discount * undiscounted
+    }
+
+    /// Calculate Delta (sensitivity to the forward).
+    pub fn delta(&self) -> f64 {
+        if self.volatility <= 0.0 || self.time_to_expiry <= 0.0 {
+            return match self.option_type {
+                OptionType::Call => {
+                    if self.forward > self.strike {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }
+                OptionType::Put => {
+                    if self.forward < self.strike {
+                        -1.0
+                    } else {
+                        0.0
+                    }
+                }
+            };
+        }
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let d = self.d();
+
+        match self.option_type {
+            OptionType::Call => normal.cdf(d),
+            OptionType::Put => normal.cdf(d) - 1.0,
+        }
+    }
+
+    /// Calculate Gamma (rate of change of Delta with respect to the forward).
+    pub fn gamma(&self) -> f64 {
+        if self.volatility <= 0.0 || self.time_to_expiry <= 0.0 {
+            return 0.0;
+        }
+
+        Self::pdf(self.d()) / (self.volatility * self.time_to_expiry.sqrt())
+    }
+
+    /// Calculate Vega (sensitivity to the normal volatility).
+    pub fn vega(&self) -> f64 {
+        if self.time_to_expiry <= 0.0 {
+            return 0.0;
+        }
+
+        self.time_to_expiry.sqrt() * Self::pdf(self.d())
+    }
+
+    /// Calculate the implied *normal* volatility using Newton-Raphson,
+    /// seeded from the standard at-the-money normal approximation
+    /// `price ≈ discount · 0.4 · σ · √T`.
+    pub fn implied_normal_volatility(
+        forward: f64,
+        strike: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        market_price: f64,
+        option_type: OptionType,
+    ) -> Option<f64> {
+        let discount = (-risk_free_rate * time_to_expiry).exp();
+        let mut volatility =
+            (market_price / (discount * 0.4 * time_to_expiry.sqrt())).max(1e-4);
+        let tolerance = 1e-6;
+        let max_iterations = 100;
+
+        for _ in 0..max_iterations {
+            let model = Bachelier::new(
+                forward,
+                strike,
+                time_to_expiry,
+                risk_free_rate,
+                volatility,
+                option_type,
+            );
+
+            let price = model.price();
+            let vega = model.vega() * discount;
+
+            let diff = market_price - price;
+
+            if diff.abs() < tolerance {
+                return Some(volatility);
+            }
+
+            if vega.abs() < 1e-10 {
+                return None;
+            }
+
+            volatility += diff / vega;
+
+            if volatility <= 0.0 {
+                return None;
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_call_price_positive() {
+        let model = Bachelier::new(100.0, 100.0, 1.0, 0.05, 20.0, OptionType::Call);
+        let price = model.price();
+        assert!(price > 0.0);
+    }
+
+    #[test]
+    fn test_put_call_parity() {
+        let forward = 100.0;
+        let strike = 95.0;
+        let time = 1.0;
+        let rate = 0.03;
+        let vol = 15.0;
+
+        let call = Bachelier::new(forward, strike, time, rate, vol, OptionType::Call);
+        let put = Bachelier::new(forward, strike, time, rate, vol, OptionType::Put);
+
+        // Put-Call Parity: C - P = discount * (F - K)
+        let lhs = call.price() - put.price();
+        let rhs = (-rate * time).exp() * (forward - strike);
+
+        assert_relative_eq!(lhs, rhs, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_negative_forward_is_well_defined() {
+        // Bachelier must price sensibly even when the forward is negative,
+        // unlike Black-Scholes where ln(S/K) would be undefined.
+        let model = Bachelier::new(-10.0, -5.0, 1.0, 0.02, 8.0, OptionType::Call);
+        let price = model.price();
+        assert!(price > 0.0);
+        assert!(price.is_finite());
+    }
+
+    #[test]
+    fn test_vol_to_zero_limit_is_intrinsic_value() {
+        let call = Bachelier::new(110.0, 100.0, 1.0, 0.05, 0.0, OptionType::Call);
+        let discount = (-0.05_f64).exp();
+        assert_relative_eq!(call.price(), discount * 10.0, epsilon = 1e-12);
+
+        let otm_put = Bachelier::new(110.0, 100.0, 1.0, 0.05, 0.0, OptionType::Put);
+        assert_relative_eq!(otm_put.price(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_implied_normal_volatility_round_trips() {
+        let forward = 100.0;
+        let strike = 105.0;
+        let time = 0.5;
+        let rate = 0.04;
+        let vol = 12.0;
+
+        let model = Bachelier::new(forward, strike, time, rate, vol, OptionType::Call);
+        let market_price = model.price();
+
+        let implied = Bachelier::implied_normal_volatility(
+            forward,
+            strike,
+            time,
+            rate,
+            market_price,
+            OptionType::Call,
+        );
+
+        assert!(implied.is_some());
+        assert_relative_eq!(implied.unwrap(), vol, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::black_scholes::OptionType;
+
+/// Exercise style supported by the lattice.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExerciseStyle {
+    European,
+    American,
+}
+
+/// Cox-Ross-Rubinstein binomial tree option pricer.
+///
+/// Unlike [`crate::BlackScholes`] and [`crate::MonteCarloSimulator`], which only
+/// value European payoffs, `BinomialTree` supports American early exercise by
+/// comparing continuation value against intrinsic value at every interior node.
+pub struct BinomialTree {
+    spot_price: f64,
+    strike_price: f64,
+    time_to_expiry: f64,
+    risk_free_rate: f64,
+    dividend_yield: f64,
+    volatility: f64,
+    steps: usize,
+    option_type: OptionType,
+    exercise_style: ExerciseStyle,
+}
+
+impl BinomialTree {
+    /// Default number of time steps used by [`BinomialTree::new`].
+    pub const DEFAULT_STEPS: usize = 1000;
+
+    /// Create a new binomial tree pricer with the default step count (1000).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        option_type: OptionType,
+        exercise_style: ExerciseStyle,
+    ) -> Self {
+        Self::with_steps(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            option_type,
+            exercise_style,
+            Self::DEFAULT_STEPS,
+        )
+    }
+
+    /// Create a new binomial tree pricer with an explicit number of time steps.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_steps(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        option_type: OptionType,
+        exercise_style: ExerciseStyle,
+        steps: usize,
+    ) -> Self {
+        assert!(steps >= 2, "binomial tree needs at least 2 steps");
+        Self {
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            dividend_yield,
+            volatility,
+            steps,
+            option_type,
+            exercise_style,
+        }
+    }
+
+    fn intrinsic(&self, spot: f64) -> f64 {
+        match self.option_type {
+            OptionType::Call => (spot - self.strike_price).max(0.0),
+            OptionType::Put => (self.strike_price - spot).max(0.0),
+        }
+    }
+
+    /// Build the lattice parameters shared by pricing and the Greeks:
+    /// `(dt, u, d, p, discount)`.
+    fn lattice_params(&self) -> (f64, f64, f64, f64, f64) {
+        let dt = self.time_to_expiry / self.steps as f64;
+        let u = (self.volatility * dt.sqrt()).exp();
+        let d = 1.0 / u;
+        let growth = ((self.risk_free_rate - self.dividend_yield) * dt).exp();
+        let p = (growth - d) / (u - d);
+        let discount = (-self.risk_free_rate * dt).exp();
+        (dt, u, d, p, discount)
+    }
+
+    /// Run backward induction over the lattice and return the option value at
+    /// every node of the requested time step (0 = root).
+    fn values_at_step(&self, target_step: usize) -> Vec<f64> {
+        let (_, u, d, p, discount) = self.lattice_params();
+        let n = self.steps;
+
+        let mut values: Vec<f64> = (0..=n)
+            .map(|j| {
+                let spot = self.spot_price * u.powi((n - j) as i32) * d.powi(j as i32);
+                self.intrinsic(spot)
+            })
+            .collect();
+
+        for step in (target_step..n).rev() {
+            for j in 0..=step {
+                let continuation = discount * (p * values[j] + (1.0 - p) * values[j + 1]);
+                values[j] = match self.exercise_style {
+                    ExerciseStyle::European => continuation,
+                    ExerciseStyle::American => {
+                        let spot = self.spot_price * u.powi((step - j) as i32) * d.powi(j as i32);
+                        continuation.max(self.intrinsic(spot))
+                    }
+                };
+            }
+            values.truncate(step + 1);
+        }
+
+        values
+    }
+
+    /// Price the option by backward induction over the full lattice.
+    pub fn price(&self) -> f64 {
+        self.values_at_step(0)[0]
+    }
+
+    /// Delta, computed from the two nodes at the first time step rather than
+    /// by finite-differencing a fresh lattice.
+    pub fn delta(&self) -> f64 {
+        let (_, u, d, _, _) = self.lattice_params();
+        let values = self.values_at_step(1);
+
+        let spot_up = self.spot_price * u;
+        let spot_down = self.spot_price * d;
+
+        (values[0] - values[1]) / (spot_up - spot_down)
+    }
+
+    /// Gamma, computed from the three nodes at the second time step.
+    pub fn gamma(&self) -> f64 {
+        let (_, u, d, _, _) = self.lattice_params();
+        let values = self.values_at_step(2);
+
+        let spot_up = self.spot_price * u * u;
+        let spot_mid = self.spot_price;
+        let spot_down = self.spot_price * d * d;
+
+        let delta_up = (values[0] - values[1]) / (spot_up - spot_mid);
+        let delta_down = (values[1] - values[2]) / (spot_mid - spot_down);
+
+        2.0 * (delta_up - delta_down) / (spot_up - spot_down)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlackScholes;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_european_call_converges_to_black_scholes() {
+        let tree = BinomialTree::with_steps(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.0,
+            0.2,
+            OptionType::Call,
+            ExerciseStyle::European,
+            1000,
+        );
+        let bs = BlackScholes::new(100.0, 100.0, 1.0, 0.05, 0.2, OptionType::Call);
+
+        assert_relative_eq!(tree.price(), bs.price(), epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_american_put_at_least_as_valuable_as_european() {
+        let american = BinomialTree::new(
+            100.0,
+            110.0,
+            1.0,
+            0.05,
+            0.0,
+            0.3,
+            OptionType::Put,
+            ExerciseStyle::American,
+        );
+        let european = BinomialTree::new(
+            100.0,
+            110.0,
+            1.0,
+            0.05,
+            0.0,
+            0.3,
+            OptionType::Put,
+            ExerciseStyle::European,
+        );
+
+        assert!(american.price() >= european.price());
+    }
+
+    #[test]
+    fn test_american_call_without_dividends_matches_european() {
+        // With no dividends, early exercise of an American call is never optimal.
+        let american = BinomialTree::new(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.0,
+            0.2,
+            OptionType::Call,
+            ExerciseStyle::American,
+        );
+        let european = BinomialTree::new(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.0,
+            0.2,
+            OptionType::Call,
+            ExerciseStyle::European,
+        );
+
+        assert_relative_eq!(american.price(), european.price(), epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_delta_range_for_call() {
+        let tree = BinomialTree::new(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.0,
+            0.2,
+            OptionType::Call,
+            ExerciseStyle::European,
+        );
+
+        let delta = tree.delta();
+        assert!((0.0..=1.0).contains(&delta));
+    }
+
+    #[test]
+    fn test_gamma_positive() {
+        let tree = BinomialTree::new(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.0,
+            0.2,
+            OptionType::Call,
+            ExerciseStyle::European,
+        );
+
+        assert!(tree.gamma() > 0.0);
+    }
+}
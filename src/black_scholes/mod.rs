@@ -7,18 +7,33 @@ pub enum OptionType {
     Put,
 }
 
-/// Black-Scholes option pricing model
+/// Black-Scholes option pricing model, generalized with a cost-of-carry
+/// parameter `b` (Black '76 / Garman-Kohlhagen form).
+///
+/// `b` lets the same formulas price several underlying types:
+/// - `b = r` recovers the classic non-dividend Black-Scholes model.
+/// - `b = r - q` prices a stock paying a continuous dividend yield `q`.
+/// - `b = r - r_foreign` prices FX options (Garman-Kohlhagen).
+/// - `b = 0` prices options on futures (Black '76).
 pub struct BlackScholes {
     spot_price: f64,
     strike_price: f64,
     time_to_expiry: f64,
     risk_free_rate: f64,
     volatility: f64,
+    cost_of_carry: f64,
     option_type: OptionType,
 }
 
 impl BlackScholes {
-    /// Create a new Black-Scholes calculator
+    /// Lower volatility bound used to bracket [`BlackScholes::implied_volatility`].
+    const IV_VOL_LOW: f64 = 1e-4;
+    /// Upper volatility bound used to bracket [`BlackScholes::implied_volatility`].
+    const IV_VOL_HIGH: f64 = 5.0;
+
+    /// Create a new Black-Scholes calculator for a non-dividend-paying underlying.
+    ///
+    /// Equivalent to [`BlackScholes::with_cost_of_carry`] with `b = risk_free_rate`.
     pub fn new(
         spot_price: f64,
         strike_price: f64,
@@ -26,6 +41,53 @@ impl BlackScholes {
         risk_free_rate: f64,
         volatility: f64,
         option_type: OptionType,
+    ) -> Self {
+        Self::with_cost_of_carry(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            risk_free_rate,
+            volatility,
+            option_type,
+        )
+    }
+
+    /// Create a calculator for an underlying paying a continuous dividend yield `q`.
+    ///
+    /// Internally this is `with_cost_of_carry` with `b = risk_free_rate - dividend_yield`.
+    pub fn with_dividend_yield(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        dividend_yield: f64,
+        volatility: f64,
+        option_type: OptionType,
+    ) -> Self {
+        Self::with_cost_of_carry(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            risk_free_rate - dividend_yield,
+            volatility,
+            option_type,
+        )
+    }
+
+    /// Create a calculator with an explicit cost-of-carry `b`, the most general form.
+    ///
+    /// This is the form to reach for when `b` is not simply `r - q`, e.g. FX options
+    /// (`b = r - r_foreign`) or options on futures/commodities (`b = 0`).
+    pub fn with_cost_of_carry(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        cost_of_carry: f64,
+        volatility: f64,
+        option_type: OptionType,
     ) -> Self {
         Self {
             spot_price,
@@ -33,6 +95,7 @@ impl BlackScholes {
             time_to_expiry,
             risk_free_rate,
             volatility,
+            cost_of_carry,
             option_type,
         }
     }
@@ -40,7 +103,7 @@ impl BlackScholes {
     /// Calculate d1 parameter
     fn d1(&self) -> f64 {
         let numerator = (self.spot_price / self.strike_price).ln()
-            + (self.risk_free_rate + 0.5 * self.volatility.powi(2)) * self.time_to_expiry;
+            + (self.cost_of_carry + 0.5 * self.volatility.powi(2)) * self.time_to_expiry;
         let denominator = self.volatility * self.time_to_expiry.sqrt();
         numerator / denominator
     }
@@ -50,23 +113,31 @@ impl BlackScholes {
         self.d1() - self.volatility * self.time_to_expiry.sqrt()
     }
 
+    /// `e^((b-r)T)`, the carry discount factor applied to the spot price.
+    fn carry_factor(&self) -> f64 {
+        ((self.cost_of_carry - self.risk_free_rate) * self.time_to_expiry).exp()
+    }
+
+    fn pdf(x: f64) -> f64 {
+        (-0.5 * x.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt()
+    }
+
     /// Calculate option price
     pub fn price(&self) -> f64 {
         let normal = Normal::new(0.0, 1.0).unwrap();
         let d1 = self.d1();
         let d2 = self.d2();
+        let carry_factor = self.carry_factor();
+        let discount = (-self.risk_free_rate * self.time_to_expiry).exp();
 
         match self.option_type {
             OptionType::Call => {
-                self.spot_price * normal.cdf(d1)
-                    - self.strike_price
-                        * (-self.risk_free_rate * self.time_to_expiry).exp()
-                        * normal.cdf(d2)
+                self.spot_price * carry_factor * normal.cdf(d1)
+                    - self.strike_price * discount * normal.cdf(d2)
             }
             OptionType::Put => {
-                self.strike_price * (-self.risk_free_rate * self.time_to_expiry).exp()
-                    * normal.cdf(-d2)
-                    - self.spot_price * normal.cdf(-d1)
+                self.strike_price * discount * normal.cdf(-d2)
+                    - self.spot_price * carry_factor * normal.cdf(-d1)
             }
         }
     }
@@ -75,29 +146,28 @@ impl BlackScholes {
     pub fn delta(&self) -> f64 {
         let normal = Normal::new(0.0, 1.0).unwrap();
         let d1 = self.d1();
+        let carry_factor = self.carry_factor();
 
         match self.option_type {
-            OptionType::Call => normal.cdf(d1),
-            OptionType::Put => normal.cdf(d1) - 1.0,
+            OptionType::Call => carry_factor * normal.cdf(d1),
+            OptionType::Put => carry_factor * (normal.cdf(d1) - 1.0),
         }
     }
 
     /// Calculate Gamma (rate of change of Delta with respect to underlying price)
     pub fn gamma(&self) -> f64 {
-        let normal = Normal::new(0.0, 1.0).unwrap();
         let d1 = self.d1();
-        let pdf = (-0.5 * d1.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let carry_factor = self.carry_factor();
 
-        pdf / (self.spot_price * self.volatility * self.time_to_expiry.sqrt())
+        carry_factor * Self::pdf(d1) / (self.spot_price * self.volatility * self.time_to_expiry.sqrt())
     }
 
     /// Calculate Vega (sensitivity to volatility)
     pub fn vega(&self) -> f64 {
-        let normal = Normal::new(0.0, 1.0).unwrap();
         let d1 = self.d1();
-        let pdf = (-0.5 * d1.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let carry_factor = self.carry_factor();
 
-        self.spot_price * pdf * self.time_to_expiry.sqrt() / 100.0
+        self.spot_price * carry_factor * Self::pdf(d1) * self.time_to_expiry.sqrt() / 100.0
     }
 
     /// Calculate Theta (time decay)
@@ -105,52 +175,73 @@ impl BlackScholes {
         let normal = Normal::new(0.0, 1.0).unwrap();
         let d1 = self.d1();
         let d2 = self.d2();
-        let pdf = (-0.5 * d1.powi(2)).exp() / (2.0 * std::f64::consts::PI).sqrt();
+        let carry_factor = self.carry_factor();
+        let discount = (-self.risk_free_rate * self.time_to_expiry).exp();
 
-        let term1 = -(self.spot_price * pdf * self.volatility)
+        let term1 = -(self.spot_price * carry_factor * Self::pdf(d1) * self.volatility)
             / (2.0 * self.time_to_expiry.sqrt());
 
         match self.option_type {
             OptionType::Call => {
-                let term2 = self.risk_free_rate
-                    * self.strike_price
-                    * (-self.risk_free_rate * self.time_to_expiry).exp()
-                    * normal.cdf(d2);
-                (term1 - term2) / 365.0
+                let term2 = (self.cost_of_carry - self.risk_free_rate)
+                    * self.spot_price
+                    * carry_factor
+                    * normal.cdf(d1);
+                let term3 = self.risk_free_rate * self.strike_price * discount * normal.cdf(d2);
+                (term1 - term2 - term3) / 365.0
             }
             OptionType::Put => {
-                let term2 = self.risk_free_rate
-                    * self.strike_price
-                    * (-self.risk_free_rate * self.time_to_expiry).exp()
-                    * normal.cdf(-d2);
-                (term1 + term2) / 365.0
+                let term2 = (self.cost_of_carry - self.risk_free_rate)
+                    * self.spot_price
+                    * carry_factor
+                    * normal.cdf(-d1);
+                let term3 = self.risk_free_rate * self.strike_price * discount * normal.cdf(-d2);
+                (term1 + term2 + term3) / 365.0
             }
         }
     }
 
-    /// Calculate Rho (sensitivity to interest rate)
+    /// Calculate Rho (sensitivity to the risk-free rate `r`, holding the cost-of-carry `b` fixed).
     pub fn rho(&self) -> f64 {
         let normal = Normal::new(0.0, 1.0).unwrap();
+        let d1 = self.d1();
         let d2 = self.d2();
+        let carry_factor = self.carry_factor();
+        let discount = (-self.risk_free_rate * self.time_to_expiry).exp();
 
         match self.option_type {
             OptionType::Call => {
-                self.strike_price
-                    * self.time_to_expiry
-                    * (-self.risk_free_rate * self.time_to_expiry).exp()
-                    * normal.cdf(d2)
+                (self.strike_price * self.time_to_expiry * discount * normal.cdf(d2)
+                    - self.spot_price * self.time_to_expiry * carry_factor * normal.cdf(d1))
                     / 100.0
             }
             OptionType::Put => {
-                -self.strike_price
-                    * self.time_to_expiry
-                    * (-self.risk_free_rate * self.time_to_expiry).exp()
-                    * normal.cdf(-d2)
+                (self.spot_price * self.time_to_expiry * carry_factor * normal.cdf(-d1)
+                    - self.strike_price * self.time_to_expiry * discount * normal.cdf(-d2))
                     / 100.0
             }
         }
     }
 
+    /// Calculate Rho on the cost-of-carry `b` (sensitivity to `b` holding `r` fixed).
+    ///
+    /// For stocks with a dividend yield this is (the negative of) sensitivity to `q`;
+    /// for FX/futures it is the sensitivity to the foreign rate / carry term directly.
+    pub fn rho_carry(&self) -> f64 {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let d1 = self.d1();
+        let carry_factor = self.carry_factor();
+
+        match self.option_type {
+            OptionType::Call => {
+                self.spot_price * self.time_to_expiry * carry_factor * normal.cdf(d1) / 100.0
+            }
+            OptionType::Put => {
+                -self.spot_price * self.time_to_expiry * carry_factor * normal.cdf(-d1) / 100.0
+            }
+        }
+    }
+
     /// Calculate all Greeks at once
     pub fn greeks(&self) -> Greeks {
         Greeks {
@@ -159,10 +250,18 @@ impl BlackScholes {
             vega: self.vega(),
             theta: self.theta(),
             rho: self.rho(),
+            rho_carry: self.rho_carry(),
         }
     }
 
-    /// Calculate implied volatility using Newton-Raphson method
+    /// Calculate implied volatility.
+    ///
+    /// Brackets the root between [`Self::IV_VOL_LOW`] and [`Self::IV_VOL_HIGH`] first,
+    /// then runs Newton-Raphson inside that bracket, falling back to a bisection step
+    /// whenever a Newton step would leave the bracket or vega is too small to trust.
+    /// This converges for any `market_price` that is arbitrage-free for the bracket,
+    /// including the deep ITM/OTM and short-dated cases where bare Newton-Raphson
+    /// from a fixed guess tends to diverge or get rejected by its `(0, 5]` guard.
     pub fn implied_volatility(
         spot_price: f64,
         strike_price: f64,
@@ -170,11 +269,36 @@ impl BlackScholes {
         risk_free_rate: f64,
         market_price: f64,
         option_type: OptionType,
-    ) -> Option<f64> {
-        let mut volatility = 0.5; // Initial guess
+    ) -> Result<f64, ImpliedVolatilityError> {
         let tolerance = 1e-6;
         let max_iterations = 100;
 
+        let price_at = |volatility: f64| {
+            BlackScholes::new(
+                spot_price,
+                strike_price,
+                time_to_expiry,
+                risk_free_rate,
+                volatility,
+                option_type,
+            )
+            .price()
+        };
+
+        let mut lo = Self::IV_VOL_LOW;
+        let mut hi = Self::IV_VOL_HIGH;
+        let price_lo = price_at(lo);
+        let price_hi = price_at(hi);
+
+        if market_price < price_lo {
+            return Err(ImpliedVolatilityError::BelowIntrinsic);
+        }
+        if market_price > price_hi {
+            return Err(ImpliedVolatilityError::AboveNoArbitrageBound);
+        }
+
+        let mut volatility = 0.5_f64.clamp(lo, hi);
+
         for _ in 0..max_iterations {
             let bs = BlackScholes::new(
                 spot_price,
@@ -186,29 +310,48 @@ impl BlackScholes {
             );
 
             let price = bs.price();
-            let vega = bs.vega() * 100.0; // Convert back to percentage
-
             let diff = market_price - price;
 
             if diff.abs() < tolerance {
-                return Some(volatility);
+                return Ok(volatility);
             }
 
-            if vega.abs() < 1e-10 {
-                return None; // Avoid division by zero
+            if diff > 0.0 {
+                lo = volatility;
+            } else {
+                hi = volatility;
             }
 
-            volatility += diff / vega;
+            let vega = bs.vega() * 100.0; // Convert back to percentage
+            let newton_step = volatility + diff / vega;
 
-            if volatility <= 0.0 || volatility > 5.0 {
-                return None; // Invalid volatility
-            }
+            volatility = if vega.abs() < 1e-10 || newton_step <= lo || newton_step >= hi {
+                (lo + hi) / 2.0 // Bisection fallback, always stays inside the bracket
+            } else {
+                newton_step
+            };
         }
 
-        None // Did not converge
+        Err(ImpliedVolatilityError::DidNotConverge)
     }
 }
 
+/// Failure modes for [`BlackScholes::implied_volatility`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImpliedVolatilityError {
+    /// `market_price` is below the price at [`BlackScholes::IV_VOL_LOW`], i.e. below
+    /// the no-arbitrage floor for this option, which at that volatility is essentially
+    /// the discounted intrinsic value: `max(S - K·e^(-rT), 0)` for a call and
+    /// `max(K·e^(-rT) - S, 0)` for a put.
+    BelowIntrinsic,
+    /// `market_price` is above the price at [`BlackScholes::IV_VOL_HIGH`], i.e. above
+    /// the no-arbitrage ceiling reachable within the bracket.
+    AboveNoArbitrageBound,
+    /// The bracket contained the price but the solver did not converge within the
+    /// iteration budget.
+    DidNotConverge,
+}
+
 /// Greeks container
 #[derive(Debug, Clone, Copy)]
 pub struct Greeks {
@@ -217,6 +360,7 @@ pub struct Greeks {
     pub vega: f64,
     pub theta: f64,
     pub rho: f64,
+    pub rho_carry: f64,
 }
 
 #[cfg(test)]
@@ -306,7 +450,105 @@ mod tests {
             OptionType::Call,
         );
 
-        assert!(implied_vol.is_some());
+        assert!(implied_vol.is_ok());
         assert_relative_eq!(implied_vol.unwrap(), vol, epsilon = 1e-4);
     }
+
+    #[test]
+    fn test_implied_volatility_deep_otm_short_dated() {
+        // Out-of-the-money, short-dated call (strike 20% above spot, 0.1 years):
+        // the solver should recover the volatility that produced the price.
+        let spot = 100.0;
+        let strike = 120.0;
+        let time = 0.1;
+        let rate = 0.05;
+        let vol = 0.6;
+
+        let bs = BlackScholes::new(spot, strike, time, rate, vol, OptionType::Call);
+        let market_price = bs.price();
+
+        let implied_vol =
+            BlackScholes::implied_volatility(spot, strike, time, rate, market_price, OptionType::Call);
+
+        assert!(implied_vol.is_ok());
+        assert_relative_eq!(implied_vol.unwrap(), vol, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_implied_volatility_deep_itm_short_dated() {
+        let spot = 100.0;
+        let strike = 120.0;
+        let time = 0.1;
+        let rate = 0.05;
+        let vol = 0.6;
+
+        let bs = BlackScholes::new(spot, strike, time, rate, vol, OptionType::Put);
+        let market_price = bs.price();
+
+        let implied_vol =
+            BlackScholes::implied_volatility(spot, strike, time, rate, market_price, OptionType::Put);
+
+        assert!(implied_vol.is_ok());
+        assert_relative_eq!(implied_vol.unwrap(), vol, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_implied_volatility_below_intrinsic_is_rejected() {
+        // A call struck at 100 with spot 150 must be worth at least the discounted
+        // intrinsic value; a quoted price of 1.0 is below any arbitrage-free bound.
+        let result = BlackScholes::implied_volatility(150.0, 100.0, 1.0, 0.05, 1.0, OptionType::Call);
+
+        assert_eq!(result, Err(ImpliedVolatilityError::BelowIntrinsic));
+    }
+
+    #[test]
+    fn test_implied_volatility_above_no_arbitrage_bound_is_rejected() {
+        // No call can ever be worth more than the spot price itself.
+        let result =
+            BlackScholes::implied_volatility(100.0, 100.0, 1.0, 0.05, 150.0, OptionType::Call);
+
+        assert_eq!(result, Err(ImpliedVolatilityError::AboveNoArbitrageBound));
+    }
+
+    #[test]
+    fn test_dividend_yield_reduces_call_price() {
+        let no_div = BlackScholes::new(100.0, 100.0, 1.0, 0.05, 0.2, OptionType::Call);
+        let with_div = BlackScholes::with_dividend_yield(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.03,
+            0.2,
+            OptionType::Call,
+        );
+
+        assert!(with_div.price() < no_div.price());
+    }
+
+    #[test]
+    fn test_cost_of_carry_zero_matches_futures_style_pricing() {
+        // b = 0 prices options on futures (Black '76); zero carry should discount
+        // the spot term by e^(-rT), same as the strike term.
+        let futures_style =
+            BlackScholes::with_cost_of_carry(100.0, 100.0, 1.0, 0.05, 0.0, 0.2, OptionType::Call);
+        let discount = (-0.05_f64).exp();
+
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let d1 = ((100.0_f64 / 100.0).ln() + 0.5 * 0.2_f64.powi(2)) / 0.2;
+        let d2 = d1 - 0.2;
+        let expected = discount * (100.0 * normal.cdf(d1) - 100.0 * normal.cdf(d2));
+
+        assert_relative_eq!(futures_style.price(), expected, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_with_cost_of_carry_matches_new_when_b_equals_r() {
+        let base = BlackScholes::new(100.0, 95.0, 0.5, 0.04, 0.3, OptionType::Put);
+        let explicit =
+            BlackScholes::with_cost_of_carry(100.0, 95.0, 0.5, 0.04, 0.04, 0.3, OptionType::Put);
+
+        assert_relative_eq!(base.price(), explicit.price(), epsilon = 1e-12);
+        assert_relative_eq!(base.rho(), explicit.rho(), epsilon = 1e-12);
+    }
 }
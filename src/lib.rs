@@ -1,5 +1,12 @@
+pub mod bachelier;
+pub mod binomial;
 pub mod black_scholes;
 pub mod monte_carlo;
 
-pub use black_scholes::{BlackScholes, Greeks, OptionType};
-pub use monte_carlo::MonteCarloSimulator;
+pub use bachelier::Bachelier;
+pub use binomial::{BinomialTree, ExerciseStyle};
+pub use black_scholes::{BlackScholes, Greeks, ImpliedVolatilityError, OptionType};
+pub use monte_carlo::{
+    AverageType, BarrierDirection, BarrierKnock, ControlVariate, MonteCarloSimulator,
+    NormalMethod, PayoffSpec,
+};
@@ -50,7 +50,7 @@ fn main() {
     // Implied Volatility
     println!("\n--- Implied Volatility ---");
     let market_price = 12.5;
-    if let Some(iv) = BlackScholes::implied_volatility(
+    match BlackScholes::implied_volatility(
         spot_price,
         strike_price,
         time_to_expiry,
@@ -58,8 +58,11 @@ fn main() {
         market_price,
         OptionType::Call,
     ) {
-        println!("Market Price: ${:.2}", market_price);
-        println!("Implied Volatility: {:.2}%", iv * 100.0);
+        Ok(iv) => {
+            println!("Market Price: ${:.2}", market_price);
+            println!("Implied Volatility: {:.2}%", iv * 100.0);
+        }
+        Err(e) => println!("Could not solve for implied volatility: {:?}", e),
     }
 
     // Monte Carlo simulation
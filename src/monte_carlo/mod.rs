@@ -1,6 +1,78 @@
-use rand::thread_rng;
+use rand::rngs::StdRng;
+use rand::{thread_rng, Rng, RngCore, SeedableRng};
 use rand_distr::{Distribution, Normal};
-use crate::black_scholes::OptionType;
+use crate::black_scholes::{BlackScholes, OptionType};
+
+/// How to draw standard normal shocks from the underlying RNG.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalMethod {
+    /// Sample via `rand_distr::Normal` (the default, and the fastest option).
+    RandDistr,
+    /// Sample via a Box-Muller transform built on raw uniform draws, for
+    /// environments that want no dependency beyond `rand` itself.
+    BoxMuller,
+}
+
+/// Averaging convention for Asian payoffs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AverageType {
+    Arithmetic,
+    Geometric,
+}
+
+/// Which side of the underlying's path a barrier watches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BarrierDirection {
+    Up,
+    Down,
+}
+
+/// Whether crossing the barrier activates or extinguishes the payoff.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BarrierKnock {
+    In,
+    Out,
+}
+
+/// The payoff to apply at maturity, evaluated from the simulated path.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PayoffSpec {
+    /// Plain European payoff on the terminal price.
+    Vanilla,
+    /// Asian option struck at `strike_price`, paid on the path average.
+    AsianAveragePrice(AverageType),
+    /// Asian option paid on the terminal price against the path average as strike.
+    AsianAverageStrike(AverageType),
+    /// Knock-in/knock-out barrier wrapped around the vanilla payoff.
+    Barrier {
+        direction: BarrierDirection,
+        knock: BarrierKnock,
+        level: f64,
+    },
+    /// Lookback paid against the best/worst price reached along the path.
+    LookbackFixedStrike,
+    /// Lookback struck at the path's running minimum/maximum.
+    LookbackFloatingStrike,
+}
+
+/// Which quantity to use as a control variate in [`MonteCarloSimulator::price_with_confidence`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ControlVariate {
+    /// The discounted terminal stock price, whose expectation is analytically `spot_price`.
+    StockPrice,
+    /// The discounted vanilla payoff of the same path, whose expectation is the
+    /// analytic Black-Scholes price.
+    BlackScholes,
+}
+
+/// Running statistics accumulated while generating one simulated path.
+struct PathStats {
+    final_price: f64,
+    arithmetic_mean: f64,
+    geometric_mean: f64,
+    min: f64,
+    max: f64,
+}
 
 /// Monte Carlo option pricing simulator
 pub struct MonteCarloSimulator {
@@ -11,10 +83,17 @@ pub struct MonteCarloSimulator {
     volatility: f64,
     num_simulations: usize,
     option_type: OptionType,
+    num_steps: usize,
+    payoff_spec: PayoffSpec,
+    antithetic: bool,
+    control_variate: Option<ControlVariate>,
+    rng_seed: Option<u64>,
+    normal_method: NormalMethod,
 }
 
 impl MonteCarloSimulator {
-    /// Create a new Monte Carlo simulator
+    /// Create a new Monte Carlo simulator pricing a vanilla European payoff
+    /// with a single terminal GBM step.
     pub fn new(
         spot_price: f64,
         strike_price: f64,
@@ -24,6 +103,139 @@ impl MonteCarloSimulator {
         num_simulations: usize,
         option_type: OptionType,
     ) -> Self {
+        Self::with_path_dependent_payoff(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            volatility,
+            num_simulations,
+            option_type,
+            1,
+            PayoffSpec::Vanilla,
+        )
+    }
+
+    /// Create a simulator that evolves `num_steps` discrete GBM steps per path
+    /// and evaluates `payoff_spec` against the accumulated path statistics.
+    /// Use this for Asian, barrier, and lookback payoffs.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_path_dependent_payoff(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        num_simulations: usize,
+        option_type: OptionType,
+        num_steps: usize,
+        payoff_spec: PayoffSpec,
+    ) -> Self {
+        Self::with_variance_reduction(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            volatility,
+            num_simulations,
+            option_type,
+            num_steps,
+            payoff_spec,
+            false,
+            None,
+        )
+    }
+
+    /// Create a simulator with opt-in variance reduction: `antithetic` pairs
+    /// each draw with its mirror image, and `control_variate`, if set, corrects
+    /// the estimate using a quantity with a known analytic expectation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_variance_reduction(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        num_simulations: usize,
+        option_type: OptionType,
+        num_steps: usize,
+        payoff_spec: PayoffSpec,
+        antithetic: bool,
+        control_variate: Option<ControlVariate>,
+    ) -> Self {
+        Self::with_rng_config(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            volatility,
+            num_simulations,
+            option_type,
+            num_steps,
+            payoff_spec,
+            antithetic,
+            control_variate,
+            None,
+            NormalMethod::RandDistr,
+        )
+    }
+
+    /// Create a simulator driven by a deterministic PRNG seed instead of
+    /// `thread_rng`, so runs are exactly reproducible across machines and
+    /// can be checked against exact-value regression tests.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_seed(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        num_simulations: usize,
+        option_type: OptionType,
+        num_steps: usize,
+        payoff_spec: PayoffSpec,
+        antithetic: bool,
+        control_variate: Option<ControlVariate>,
+        seed: u64,
+    ) -> Self {
+        Self::with_rng_config(
+            spot_price,
+            strike_price,
+            time_to_expiry,
+            risk_free_rate,
+            volatility,
+            num_simulations,
+            option_type,
+            num_steps,
+            payoff_spec,
+            antithetic,
+            control_variate,
+            Some(seed),
+            NormalMethod::RandDistr,
+        )
+    }
+
+    /// Create a simulator with full control over the RNG: an optional
+    /// deterministic seed (falling back to `thread_rng` when `None`) and the
+    /// normal-sampling method. This is the fully general constructor every
+    /// other `with_*` constructor delegates into.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_rng_config(
+        spot_price: f64,
+        strike_price: f64,
+        time_to_expiry: f64,
+        risk_free_rate: f64,
+        volatility: f64,
+        num_simulations: usize,
+        option_type: OptionType,
+        num_steps: usize,
+        payoff_spec: PayoffSpec,
+        antithetic: bool,
+        control_variate: Option<ControlVariate>,
+        rng_seed: Option<u64>,
+        normal_method: NormalMethod,
+    ) -> Self {
+        assert!(num_steps >= 1, "need at least 1 time step");
         Self {
             spot_price,
             strike_price,
@@ -32,6 +244,12 @@ impl MonteCarloSimulator {
             volatility,
             num_simulations,
             option_type,
+            num_steps,
+            payoff_spec,
+            antithetic,
+            control_variate,
+            rng_seed,
+            normal_method,
         }
     }
 
@@ -42,22 +260,149 @@ impl MonteCarloSimulator {
         self.spot_price * (drift + diffusion).exp()
     }
 
+    /// Construct the RNG this simulator draws from: a deterministic `StdRng`
+    /// when `rng_seed` is set, otherwise `thread_rng`.
+    fn make_rng(&self) -> Box<dyn RngCore> {
+        match self.rng_seed {
+            Some(seed) => Box::new(StdRng::seed_from_u64(seed)),
+            None => Box::new(thread_rng()),
+        }
+    }
+
+    /// Draw one standard normal shock via `normal_method`.
+    fn sample_normal(&self, rng: &mut dyn RngCore, normal: &Normal<f64>) -> f64 {
+        match self.normal_method {
+            NormalMethod::RandDistr => normal.sample(rng),
+            NormalMethod::BoxMuller => Self::box_muller(rng),
+        }
+    }
+
+    /// Box-Muller transform: accept uniform draws `(x, y)` in `[-1, 1]` with
+    /// `s = x² + y² <= 1` and return `x·√(-2·ln(s)/s)`, a standard normal
+    /// sample with no dependency on `rand_distr`.
+    fn box_muller(rng: &mut dyn RngCore) -> f64 {
+        loop {
+            let x: f64 = rng.gen_range(-1.0..1.0);
+            let y: f64 = rng.gen_range(-1.0..1.0);
+            let s = x * x + y * y;
+            if s > 0.0 && s <= 1.0 {
+                return x * (-2.0 * s.ln() / s).sqrt();
+            }
+        }
+    }
+
+    /// Draw the standard normal shocks driving one path, one per time step.
+    fn draw_shocks(&self, normal: &Normal<f64>, rng: &mut dyn RngCore) -> Vec<f64> {
+        (0..self.num_steps)
+            .map(|_| self.sample_normal(rng, normal))
+            .collect()
+    }
+
+    /// Evolve a full `num_steps`-step GBM path from a fixed sequence of normal
+    /// shocks and collect the statistics needed by every payoff in [`PayoffSpec`].
+    /// Taking the shocks as input (rather than sampling them here) is what lets
+    /// antithetic variates reuse the same draws negated.
+    fn path_from_shocks(&self, shocks: &[f64]) -> PathStats {
+        let dt = self.time_to_expiry / self.num_steps as f64;
+        let drift = (self.risk_free_rate - 0.5 * self.volatility.powi(2)) * dt;
+        let vol_sqrt_dt = self.volatility * dt.sqrt();
+
+        let mut price = self.spot_price;
+        let mut sum = 0.0;
+        let mut sum_log = 0.0;
+        let mut min = price;
+        let mut max = price;
+
+        for &z in shocks {
+            price *= (drift + vol_sqrt_dt * z).exp();
+            sum += price;
+            sum_log += price.ln();
+            min = min.min(price);
+            max = max.max(price);
+        }
+
+        PathStats {
+            final_price: price,
+            arithmetic_mean: sum / self.num_steps as f64,
+            geometric_mean: (sum_log / self.num_steps as f64).exp(),
+            min,
+            max,
+        }
+    }
+
+    /// Vanilla call/put payoff of `price` struck at `strike`.
+    fn vanilla_payoff_against(&self, price: f64, strike: f64) -> f64 {
+        match self.option_type {
+            OptionType::Call => (price - strike).max(0.0),
+            OptionType::Put => (strike - price).max(0.0),
+        }
+    }
+
     /// Calculate option payoff
     fn payoff(&self, final_price: f64) -> f64 {
-        match self.option_type {
-            OptionType::Call => (final_price - self.strike_price).max(0.0),
-            OptionType::Put => (self.strike_price - final_price).max(0.0),
+        self.vanilla_payoff_against(final_price, self.strike_price)
+    }
+
+    /// Evaluate [`PayoffSpec`] against one simulated path.
+    fn payoff_from_path(&self, stats: &PathStats) -> f64 {
+        match self.payoff_spec {
+            PayoffSpec::Vanilla => self.payoff(stats.final_price),
+            PayoffSpec::AsianAveragePrice(average_type) => {
+                let average = match average_type {
+                    AverageType::Arithmetic => stats.arithmetic_mean,
+                    AverageType::Geometric => stats.geometric_mean,
+                };
+                self.vanilla_payoff_against(average, self.strike_price)
+            }
+            PayoffSpec::AsianAverageStrike(average_type) => {
+                let average = match average_type {
+                    AverageType::Arithmetic => stats.arithmetic_mean,
+                    AverageType::Geometric => stats.geometric_mean,
+                };
+                self.vanilla_payoff_against(stats.final_price, average)
+            }
+            PayoffSpec::Barrier {
+                direction,
+                knock,
+                level,
+            } => {
+                let breached = match direction {
+                    BarrierDirection::Up => stats.max >= level,
+                    BarrierDirection::Down => stats.min <= level,
+                };
+                let payoff_active = match knock {
+                    BarrierKnock::In => breached,
+                    BarrierKnock::Out => !breached,
+                };
+                if payoff_active {
+                    self.payoff(stats.final_price)
+                } else {
+                    0.0
+                }
+            }
+            PayoffSpec::LookbackFixedStrike => match self.option_type {
+                OptionType::Call => (stats.max - self.strike_price).max(0.0),
+                OptionType::Put => (self.strike_price - stats.min).max(0.0),
+            },
+            PayoffSpec::LookbackFloatingStrike => match self.option_type {
+                OptionType::Call => stats.final_price - stats.min,
+                OptionType::Put => stats.max - stats.final_price,
+            },
         }
     }
 
     /// Price option using Monte Carlo simulation
+    ///
+    /// This is the fast path: a single terminal GBM draw per simulation,
+    /// valid for [`PayoffSpec::Vanilla`]. Path-dependent payoffs must go
+    /// through [`MonteCarloSimulator::price_with_confidence`].
     pub fn price(&self) -> f64 {
-        let mut rng = thread_rng();
+        let mut rng = self.make_rng();
         let normal = Normal::new(0.0, 1.0).unwrap();
 
         let sum_payoffs: f64 = (0..self.num_simulations)
             .map(|_| {
-                let z = normal.sample(&mut rng);
+                let z = self.sample_normal(&mut *rng, &normal);
                 let final_price = self.simulate_price(z);
                 self.payoff(final_price)
             })
@@ -67,32 +412,116 @@ impl MonteCarloSimulator {
         average_payoff * (-self.risk_free_rate * self.time_to_expiry).exp()
     }
 
+    /// Discounted payoff and control-variate value for one path, given its shocks.
+    fn discounted_payoff_and_control(&self, shocks: &[f64], discount_factor: f64) -> (f64, f64) {
+        let stats = self.path_from_shocks(shocks);
+        let payoff = self.payoff_from_path(&stats) * discount_factor;
+
+        let control = match self.control_variate {
+            Some(ControlVariate::StockPrice) => stats.final_price * discount_factor,
+            Some(ControlVariate::BlackScholes) => self.payoff(stats.final_price) * discount_factor,
+            None => 0.0,
+        };
+
+        (payoff, control)
+    }
+
+    /// Analytic expectation of the chosen control variate.
+    fn control_expectation(&self, control_variate: ControlVariate) -> f64 {
+        match control_variate {
+            // The discounted stock price is a martingale under the risk-neutral measure.
+            ControlVariate::StockPrice => self.spot_price,
+            ControlVariate::BlackScholes => BlackScholes::new(
+                self.spot_price,
+                self.strike_price,
+                self.time_to_expiry,
+                self.risk_free_rate,
+                self.volatility,
+                self.option_type,
+            )
+            .price(),
+        }
+    }
+
     /// Price option with confidence interval
+    ///
+    /// Generates a full `num_steps`-step path per simulation and applies
+    /// `payoff_spec`, so this works for vanilla as well as every
+    /// path-dependent payoff in [`PayoffSpec`]. Honors `antithetic` and
+    /// `control_variate` if configured via [`MonteCarloSimulator::with_variance_reduction`].
     pub fn price_with_confidence(&self) -> (f64, f64, f64) {
-        let mut rng = thread_rng();
+        let mut rng = self.make_rng();
         let normal = Normal::new(0.0, 1.0).unwrap();
+        let discount_factor = (-self.risk_free_rate * self.time_to_expiry).exp();
 
-        let payoffs: Vec<f64> = (0..self.num_simulations)
+        let (payoffs, controls): (Vec<f64>, Vec<f64>) = (0..self.num_simulations)
             .map(|_| {
-                let z = normal.sample(&mut rng);
-                let final_price = self.simulate_price(z);
-                self.payoff(final_price)
+                let shocks = self.draw_shocks(&normal, &mut *rng);
+                let (payoff, control) = self.discounted_payoff_and_control(&shocks, discount_factor);
+
+                if self.antithetic {
+                    let mirrored: Vec<f64> = shocks.iter().map(|z| -z).collect();
+                    let (anti_payoff, anti_control) =
+                        self.discounted_payoff_and_control(&mirrored, discount_factor);
+                    ((payoff + anti_payoff) / 2.0, (control + anti_control) / 2.0)
+                } else {
+                    (payoff, control)
+                }
             })
-            .collect();
+            .unzip();
 
-        let mean = payoffs.iter().sum::<f64>() / self.num_simulations as f64;
-        let variance = payoffs
-            .iter()
-            .map(|p| (p - mean).powi(2))
-            .sum::<f64>()
-            / (self.num_simulations - 1) as f64;
-        let std_error = variance.sqrt() / (self.num_simulations as f64).sqrt();
+        let n = payoffs.len() as f64;
 
-        let discount_factor = (-self.risk_free_rate * self.time_to_expiry).exp();
-        let price = mean * discount_factor;
-        let confidence_interval = 1.96 * std_error * discount_factor; // 95% CI
+        let sample: Vec<f64> = if let Some(control_variate) = self.control_variate {
+            let payoff_mean = payoffs.iter().sum::<f64>() / n;
+            let control_mean = controls.iter().sum::<f64>() / n;
+
+            let covariance = payoffs
+                .iter()
+                .zip(controls.iter())
+                .map(|(p, c)| (p - payoff_mean) * (c - control_mean))
+                .sum::<f64>()
+                / (n - 1.0);
+            let control_variance = controls
+                .iter()
+                .map(|c| (c - control_mean).powi(2))
+                .sum::<f64>()
+                / (n - 1.0);
+            let optimal_c = if control_variance.abs() > 1e-12 {
+                covariance / control_variance
+            } else {
+                0.0
+            };
 
-        (price, price - confidence_interval, price + confidence_interval)
+            let expected_control = self.control_expectation(control_variate);
+            payoffs
+                .iter()
+                .zip(controls.iter())
+                .map(|(p, c)| p - optimal_c * (c - expected_control))
+                .collect()
+        } else {
+            payoffs
+        };
+
+        let mean = sample.iter().sum::<f64>() / n;
+        let variance = sample.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let std_error = (variance / n).sqrt();
+        let confidence_interval = 1.96 * std_error; // 95% CI
+
+        (mean, mean - confidence_interval, mean + confidence_interval)
+    }
+
+    /// Price a single point for the finite-difference Greeks below. Uses the
+    /// single-step fast path for [`PayoffSpec::Vanilla`] and falls back to
+    /// the full path engine (discarding the confidence bounds) for every
+    /// path-dependent payoff, since [`MonteCarloSimulator::price`] only ever
+    /// evaluates the terminal payoff and would silently bump a vanilla delta
+    /// for those specs otherwise.
+    fn price_for_bump(&self) -> f64 {
+        match self.payoff_spec {
+            PayoffSpec::Vanilla => self.price(),
+            _ => self.price_with_confidence().0,
+        }
     }
 
     /// Calculate option delta using finite difference
@@ -105,7 +534,7 @@ impl MonteCarloSimulator {
         let mut sim_down = self.clone();
         sim_down.spot_price -= epsilon;
 
-        (sim_up.price() - sim_down.price()) / (2.0 * epsilon)
+        (sim_up.price_for_bump() - sim_down.price_for_bump()) / (2.0 * epsilon)
     }
 
     /// Calculate option gamma using finite difference
@@ -118,9 +547,9 @@ impl MonteCarloSimulator {
         let mut sim_down = self.clone();
         sim_down.spot_price -= epsilon;
 
-        let price_center = self.price();
-        let price_up = sim_up.price();
-        let price_down = sim_down.price();
+        let price_center = self.price_for_bump();
+        let price_up = sim_up.price_for_bump();
+        let price_down = sim_down.price_for_bump();
 
         (price_up - 2.0 * price_center + price_down) / epsilon.powi(2)
     }
@@ -136,6 +565,12 @@ impl Clone for MonteCarloSimulator {
             volatility: self.volatility,
             num_simulations: self.num_simulations,
             option_type: self.option_type,
+            num_steps: self.num_steps,
+            payoff_spec: self.payoff_spec,
+            antithetic: self.antithetic,
+            control_variate: self.control_variate,
+            rng_seed: self.rng_seed,
+            normal_method: self.normal_method,
         }
     }
 }
@@ -191,7 +626,7 @@ mod tests {
         );
 
         let (price, lower, upper) = mc.price_with_confidence();
-        
+
         assert!(lower < price);
         assert!(price < upper);
         assert!(lower > 0.0);
@@ -212,4 +647,224 @@ mod tests {
         let delta = mc.delta();
         assert!(delta >= 0.0 && delta <= 1.0);
     }
+
+    #[test]
+    fn test_asian_average_price_cheaper_than_vanilla_call() {
+        // Averaging dampens volatility, so the Asian premium should be
+        // strictly below the vanilla European premium for the same strike.
+        let vanilla = MonteCarloSimulator::new(100.0, 100.0, 1.0, 0.05, 0.3, 20000, OptionType::Call);
+        let asian = MonteCarloSimulator::with_path_dependent_payoff(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.3,
+            20000,
+            OptionType::Call,
+            50,
+            PayoffSpec::AsianAveragePrice(AverageType::Arithmetic),
+        );
+
+        let (asian_price, _, _) = asian.price_with_confidence();
+        assert!(asian_price < vanilla.price());
+    }
+
+    #[test]
+    fn test_up_and_in_plus_up_and_out_recovers_vanilla() {
+        let steps = 50;
+        let level = 120.0;
+
+        let seed = 42;
+
+        let up_and_out = MonteCarloSimulator::with_seed(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            20000,
+            OptionType::Call,
+            steps,
+            PayoffSpec::Barrier {
+                direction: BarrierDirection::Up,
+                knock: BarrierKnock::Out,
+                level,
+            },
+            false,
+            None,
+            seed,
+        );
+        let up_and_in = MonteCarloSimulator::with_seed(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            20000,
+            OptionType::Call,
+            steps,
+            PayoffSpec::Barrier {
+                direction: BarrierDirection::Up,
+                knock: BarrierKnock::In,
+                level,
+            },
+            false,
+            None,
+            seed,
+        );
+        let vanilla = MonteCarloSimulator::with_seed(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            20000,
+            OptionType::Call,
+            steps,
+            PayoffSpec::Vanilla,
+            false,
+            None,
+            seed,
+        );
+
+        let (out_price, _, _) = up_and_out.price_with_confidence();
+        let (in_price, _, _) = up_and_in.price_with_confidence();
+        let (vanilla_price, _, _) = vanilla.price_with_confidence();
+
+        // All three simulators share the same seed, so they draw identical
+        // shock paths: in + out decomposes the vanilla payoff exactly
+        // path-by-path, and the reconstruction should match to float noise.
+        assert!((out_price + in_price - vanilla_price).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_antithetic_and_control_variate_tighten_confidence_interval() {
+        let plain = MonteCarloSimulator::with_path_dependent_payoff(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.25,
+            20000,
+            OptionType::Call,
+            1,
+            PayoffSpec::Vanilla,
+        );
+        let reduced = MonteCarloSimulator::with_variance_reduction(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.25,
+            20000,
+            OptionType::Call,
+            1,
+            PayoffSpec::Vanilla,
+            true,
+            Some(ControlVariate::BlackScholes),
+        );
+
+        let (plain_price, plain_lower, plain_upper) = plain.price_with_confidence();
+        let (reduced_price, reduced_lower, reduced_upper) = reduced.price_with_confidence();
+
+        let bs = BlackScholes::new(100.0, 100.0, 1.0, 0.05, 0.25, OptionType::Call);
+
+        // Both estimators should be in the right ballpark of the analytic price...
+        assert!((plain_price - bs.price()).abs() < 1.0);
+        assert!((reduced_price - bs.price()).abs() < 0.1);
+
+        // ...but variance reduction should produce a much tighter interval.
+        let plain_width = plain_upper - plain_lower;
+        let reduced_width = reduced_upper - reduced_lower;
+        assert!(reduced_width < plain_width / 10.0);
+    }
+
+    #[test]
+    fn test_lookback_floating_strike_call_is_positive() {
+        let mc = MonteCarloSimulator::with_path_dependent_payoff(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            5000,
+            OptionType::Call,
+            50,
+            PayoffSpec::LookbackFloatingStrike,
+        );
+
+        let (price, _, _) = mc.price_with_confidence();
+        assert!(price > 0.0);
+    }
+
+    #[test]
+    fn test_seeded_runs_are_exactly_reproducible() {
+        let mc_a = MonteCarloSimulator::with_seed(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            1000,
+            OptionType::Call,
+            1,
+            PayoffSpec::Vanilla,
+            false,
+            None,
+            42,
+        );
+        let mc_b = MonteCarloSimulator::with_seed(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            1000,
+            OptionType::Call,
+            1,
+            PayoffSpec::Vanilla,
+            false,
+            None,
+            42,
+        );
+
+        assert_eq!(mc_a.price(), mc_b.price());
+        assert_eq!(mc_a.price_with_confidence(), mc_b.price_with_confidence());
+    }
+
+    #[test]
+    fn test_box_muller_matches_rand_distr_in_distribution() {
+        let rand_distr = MonteCarloSimulator::with_rng_config(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            20000,
+            OptionType::Call,
+            1,
+            PayoffSpec::Vanilla,
+            false,
+            None,
+            Some(7),
+            NormalMethod::RandDistr,
+        );
+        let box_muller = MonteCarloSimulator::with_rng_config(
+            100.0,
+            100.0,
+            1.0,
+            0.05,
+            0.2,
+            20000,
+            OptionType::Call,
+            1,
+            PayoffSpec::Vanilla,
+            false,
+            None,
+            Some(7),
+            NormalMethod::BoxMuller,
+        );
+
+        assert!((rand_distr.price() - box_muller.price()).abs() < 1.0);
+    }
 }